@@ -1,4 +1,4 @@
-use crate::lex::{Token, TokenKind};
+use crate::lex::{Location, Token, TokenKind};
 
 #[derive(Debug)]
 pub enum Statement {
@@ -7,6 +7,11 @@ pub enum Statement {
     FunctionDeclaration(FunctionDeclaration),
     Return(Return),
     Local(Local),
+    While(While),
+    For(For),
+    Break,
+    Continue,
+    Assign(Assign),
 }
 
 pub type Ast = Vec<Statement>;
@@ -15,6 +20,9 @@ pub type Ast = Vec<Statement>;
 pub enum Literal {
     Identifier(Token),
     Number(Token),
+    String(Token),
+    Boolean(Token),
+    Nil(Token),
 }
 
 #[derive(Debug)]
@@ -30,11 +38,27 @@ pub struct BinaryOperation {
     pub right: Box<Expression>,
 }
 
+#[derive(Debug)]
+pub struct Unary {
+    pub operator: Token,
+    pub operand: Box<Expression>,
+}
+
+#[derive(Debug)]
+pub struct Logical {
+    pub operator: Token,
+    pub left: Box<Expression>,
+    pub right: Box<Expression>,
+}
+
 #[derive(Debug)]
 pub enum Expression {
     FunctionCall(FunctionCall),
     BinaryOperation(BinaryOperation),
     Literal(Literal),
+    Grouping(Box<Expression>),
+    Unary(Unary),
+    Logical(Logical),
 }
 
 #[derive(Debug)]
@@ -48,6 +72,21 @@ pub struct FunctionDeclaration {
 pub struct If {
     pub test: Expression,
     pub body: Vec<Statement>,
+    pub else_body: Option<Vec<Statement>>,
+}
+
+#[derive(Debug)]
+pub struct While {
+    pub test: Expression,
+    pub body: Vec<Statement>,
+}
+
+#[derive(Debug)]
+pub struct For {
+    pub init: Option<Box<Statement>>,
+    pub cond: Expression,
+    pub step: Option<Box<Statement>>,
+    pub body: Vec<Statement>,
 }
 
 #[derive(Debug)]
@@ -56,11 +95,55 @@ pub struct Local {
     pub expression: Expression,
 }
 
+#[derive(Debug)]
+pub struct Assign {
+    pub target: Token,
+    pub expression: Expression,
+}
+
 #[derive(Debug)]
 pub struct Return {
     pub expression: Expression,
 }
 
+/// A structured parse failure: a human-readable message plus the location it
+/// occurred at, so callers can render it themselves instead of us printing to
+/// stdout.
+#[derive(Debug, Clone)]
+pub struct ParseError {
+    pub message: String,
+    pub loc: Location,
+    // Token index the error was raised at; used to rank competing errors
+    // from the `parsers` dispatch table (see `parse_statement`) and as the
+    // point `parse_block` resumes from via `synchronize`. Not exposed to
+    // callers since `loc` already carries the user-facing position.
+    index: usize,
+}
+
+impl ParseError {
+    /// Renders a caret-underlined snippet of `raw` at the error's location,
+    /// prefixed with the message.
+    pub fn render(&self, raw: &[char]) -> String {
+        self.loc.debug(raw, &self.message)
+    }
+}
+
+fn loc_at(tokens: &[Token], index: usize) -> Location {
+    if index < tokens.len() {
+        tokens[index].loc.clone()
+    } else {
+        tokens[tokens.len() - 1].loc.clone()
+    }
+}
+
+fn parse_error<T>(tokens: &[Token], index: usize, message: &str) -> Result<T, ParseError> {
+    Err(ParseError {
+        message: message.to_string(),
+        loc: loc_at(tokens, index),
+        index,
+    })
+}
+
 fn expect_keyword(tokens: &[Token], index: usize, value: &str) -> bool {
     if index >= tokens.len() {
         return false;
@@ -88,73 +171,399 @@ fn expect_identifier(tokens: &[Token], index: usize) -> bool {
     t.kind == TokenKind::Identifier
 }
 
-fn parse_statement(raw: &[char], tokens: &[Token], index: usize) -> Option<(Statement, usize)> {
+fn parse_statement(
+    raw: &[char],
+    tokens: &[Token],
+    index: usize,
+    errors: &mut Vec<ParseError>,
+) -> Result<(Statement, usize), ParseError> {
     let parsers = [
         parse_if,
+        parse_while,
+        parse_for,
+        parse_break,
+        parse_continue,
+        parse_assign,
         parse_expression_statement,
         parse_return,
         parse_function,
         parse_local,
     ];
+
+    // Every parser in `parsers` shallow-fails at `index` itself if its leading
+    // keyword doesn't match, so most of these errors are noise. Keep the one
+    // that progressed furthest into the input instead of whichever ran last —
+    // that's the parser that actually committed to this statement and hit a
+    // real problem deeper in, not one that never matched at all.
+    let mut best_error: Option<ParseError> = None;
     for parser in parsers {
-        let res = parser(raw, tokens, index);
-        if res.is_some() {
-            return res;
+        match parser(raw, tokens, index, errors) {
+            Ok(res) => return Ok(res),
+            Err(e) => {
+                if best_error.as_ref().map_or(true, |best| e.index > best.index) {
+                    best_error = Some(e);
+                }
+            }
         }
     }
 
-    None
+    // If nothing made it past `index` at all, every one of those errors is a
+    // shallow "this isn't my keyword" mismatch (e.g. `parse_if`'s "Expected
+    // 'if' keyword:" firing for a stray `}`) rather than a real diagnostic
+    // about what the author actually typed. Report the honest, generic
+    // failure in that case instead of picking one of those mismatches.
+    match best_error {
+        Some(e) if e.index > index => Err(e),
+        _ => Err(ParseError {
+            message: "Invalid token while parsing".to_string(),
+            loc: loc_at(tokens, index),
+            index,
+        }),
+    }
 }
 
-pub fn parse(raw: &[char], tokens: Vec<Token>) -> Result<Ast, String> {
-    let mut ast = vec![];
-    let mut index = 0;
-    let ntokens = tokens.len();
-    while index < ntokens {
-        let res = parse_statement(raw, &tokens, index);
-        if let Some((stmt, next_index)) = res {
-            index = next_index;
-            ast.push(stmt);
-            continue;
+// Keywords recognized as safe resumption points by `synchronize`: statement
+// starters, plus the block/clause structure keywords (`end`, `else`,
+// `elseif`, `then`, `do`) so recovery doesn't run past the boundary of the
+// `if`/`while`/`for` construct it's inside of.
+const SYNC_KEYWORDS: [&str; 13] = [
+    "if", "while", "for", "break", "continue", "function", "return", "local", "end", "else", "elseif", "then", "do",
+];
+
+/// Skips tokens until a likely statement or block boundary: just past a `;`,
+/// or just before one of `SYNC_KEYWORDS`. Used by `parse_block` to recover
+/// from a syntax error and keep collecting diagnostics instead of aborting.
+fn synchronize(tokens: &[Token], index: usize) -> usize {
+    let mut next_index = index;
+    while next_index < tokens.len() {
+        if expect_syntax(tokens, next_index, ";") {
+            return next_index + 1;
         }
 
-        return Err(tokens[index].loc.debug(raw, "Invalid token while parsing:"));
+        next_index += 1;
+
+        if SYNC_KEYWORDS.iter().any(|kw| expect_keyword(tokens, next_index, kw)) {
+            return next_index;
+        }
     }
 
-    Ok(ast)
+    next_index
 }
 
-fn parse_expression_statement(raw: &[char], tokens: &[Token], index: usize) -> Option<(Statement, usize)> {
-    let mut next_index = index;
-    let res = parse_expression(raw, tokens, next_index)?;
+/// Parses the whole token stream, collecting every syntax error instead of
+/// stopping at the first one. Statements that fail to parse are dropped after
+/// their error is recorded and parsing resumes at the next statement boundary
+/// (see `synchronize`), so the returned `Ast` only ever contains statements
+/// that parsed successfully.
+pub fn parse(raw: &[char], tokens: Vec<Token>) -> Result<Ast, Vec<ParseError>> {
+    let mut errors = vec![];
+    let (ast, _next_index) = parse_block(raw, &tokens, 0, &[], &mut errors);
+
+    if errors.is_empty() {
+        Ok(ast)
+    } else {
+        Err(errors)
+    }
+}
+
+// Parses `identifier = expression` without consuming a trailing terminator,
+// so it can be reused both by `parse_assign` (statement form, semicolon
+// terminated) and by `parse_for`'s step clause (terminated by `do` instead).
+fn parse_assign_expr(raw: &[char], tokens: &[Token], index: usize) -> Result<(Assign, usize), ParseError> {
+    if !expect_identifier(tokens, index) || !expect_syntax(tokens, index + 1, "=") {
+        return parse_error(tokens, index, "Expected assignment target:");
+    }
 
-    let (expr, next_next_index) = res;
-    next_index = next_next_index;
+    let target = tokens[index].clone();
+    let (expression, next_index) = parse_expression(raw, tokens, index + 2, 0)?;
+
+    Ok((Assign { target, expression }, next_index))
+}
+
+// Recognizes `identifier =` lookahead so a leading identifier that reassigns
+// an existing binding isn't misparsed as an expression statement.
+fn parse_assign(raw: &[char], tokens: &[Token], index: usize, _errors: &mut Vec<ParseError>) -> Result<(Statement, usize), ParseError> {
+    let (assign, next_index) = parse_assign_expr(raw, tokens, index)?;
 
     if !expect_syntax(tokens, next_index, ";") {
-        println!(
-            "{}",
-            tokens[next_index].loc.debug(raw, "Expected semicolon after expression:")
-        );
-        return None;
+        return parse_error(tokens, next_index, "Expected semicolon after assignment:");
+    }
+
+    Ok((Statement::Assign(assign), next_index + 1))
+}
+
+fn parse_expression_statement(
+    raw: &[char],
+    tokens: &[Token],
+    index: usize,
+    _errors: &mut Vec<ParseError>,
+) -> Result<(Statement, usize), ParseError> {
+    let (expr, next_index) = parse_expression(raw, tokens, index, 0)?;
+
+    if !expect_syntax(tokens, next_index, ";") {
+        return parse_error(tokens, next_index, "Expected semicolon after expression:");
+    }
+
+    Ok((Statement::Expression(expr), next_index + 1))
+}
+
+// Parses statements until a keyword from `terminators` is seen (or the token
+// stream runs out), without consuming the terminator. Unlike a single
+// `parse_statement` call, this never aborts the whole block on the first
+// error: a statement that fails to parse has its error recorded and parsing
+// resumes at the next statement boundary (see `synchronize`), so one bad
+// statement inside an `if`/`while`/`for` body doesn't take the rest of the
+// enclosing construct down with it.
+fn parse_block(raw: &[char], tokens: &[Token], index: usize, terminators: &[&str], errors: &mut Vec<ParseError>) -> (Vec<Statement>, usize) {
+    let mut body = vec![];
+    let mut next_index = index;
+    while next_index < tokens.len() && !terminators.iter().any(|kw| expect_keyword(tokens, next_index, kw)) {
+        match parse_statement(raw, tokens, next_index, errors) {
+            Ok((stmt, next_next_index)) => {
+                next_index = next_next_index;
+                body.push(stmt);
+            }
+            Err(e) => {
+                next_index = synchronize(tokens, e.index);
+                errors.push(e);
+            }
+        }
+    }
+
+    (body, next_index)
+}
+
+fn parse_if(raw: &[char], tokens: &[Token], index: usize, errors: &mut Vec<ParseError>) -> Result<(Statement, usize), ParseError> {
+    if !expect_keyword(tokens, index, "if") {
+        return parse_error(tokens, index, "Expected 'if' keyword:");
+    }
+
+    let (if_, next_index) = parse_if_clause(raw, tokens, index + 1, errors)?;
+    Ok((Statement::If(if_), next_index))
+}
+
+// Parses the shared `<expr> then <body> [elseif ... | else ... | end]` tail of
+// both `if` and `elseif`, starting right after the `if`/`elseif` keyword.
+fn parse_if_clause(raw: &[char], tokens: &[Token], index: usize, errors: &mut Vec<ParseError>) -> Result<(If, usize), ParseError> {
+    let (test, next_index) = parse_expression(raw, tokens, index, 0)?;
+
+    if !expect_keyword(tokens, next_index, "then") {
+        return parse_error(tokens, next_index, "Expected 'then' after if condition:");
+    }
+
+    let (body, next_index) = parse_block(raw, tokens, next_index + 1, &["else", "elseif", "end"], errors);
+
+    if expect_keyword(tokens, next_index, "elseif") {
+        let (nested, next_index) = parse_if_clause(raw, tokens, next_index + 1, errors)?;
+        return Ok((
+            If {
+                test,
+                body,
+                else_body: Some(vec![Statement::If(nested)]),
+            },
+            next_index,
+        ));
+    }
+
+    if expect_keyword(tokens, next_index, "else") {
+        let (else_body, next_index) = parse_block(raw, tokens, next_index + 1, &["end"], errors);
+
+        if !expect_keyword(tokens, next_index, "end") {
+            return parse_error(tokens, next_index, "Expected 'end' after else body:");
+        }
+
+        return Ok((
+            If {
+                test,
+                body,
+                else_body: Some(else_body),
+            },
+            next_index + 1,
+        ));
+    }
+
+    if !expect_keyword(tokens, next_index, "end") {
+        return parse_error(tokens, next_index, "Expected 'end' after if body:");
+    }
+
+    Ok((
+        If {
+            test,
+            body,
+            else_body: None,
+        },
+        next_index + 1,
+    ))
+}
+
+fn parse_while(raw: &[char], tokens: &[Token], index: usize, errors: &mut Vec<ParseError>) -> Result<(Statement, usize), ParseError> {
+    if !expect_keyword(tokens, index, "while") {
+        return parse_error(tokens, index, "Expected 'while' keyword:");
     }
 
+    let (test, next_index) = parse_expression(raw, tokens, index + 1, 0)?;
+
+    if !expect_keyword(tokens, next_index, "do") {
+        return parse_error(tokens, next_index, "Expected 'do' after while condition:");
+    }
+
+    let (body, next_index) = parse_block(raw, tokens, next_index + 1, &["end"], errors);
+
+    if !expect_keyword(tokens, next_index, "end") {
+        return parse_error(tokens, next_index, "Expected 'end' after while body:");
+    }
+
+    Ok((Statement::While(While { test, body }), next_index + 1))
+}
+
+fn parse_for(raw: &[char], tokens: &[Token], index: usize, errors: &mut Vec<ParseError>) -> Result<(Statement, usize), ParseError> {
+    if !expect_keyword(tokens, index, "for") {
+        return parse_error(tokens, index, "Expected 'for' keyword:");
+    }
+
+    let mut next_index = index + 1;
+
+    // A non-empty init is parsed as a full statement, so the
+    // semicolon-terminated kinds (`parse_assign`, `parse_local`,
+    // `parse_expression_statement`, ...) already consume their own trailing
+    // `;`; an init ending in `end` (`if`, `while`, `for`) is followed
+    // directly by the condition. Only the empty init (`for ; cond; step do`)
+    // needs us to skip one here.
+    let init = if expect_syntax(tokens, next_index, ";") {
+        next_index += 1;
+        None
+    } else {
+        let (stmt, next_next_index) = parse_statement(raw, tokens, next_index, errors)?;
+        next_index = next_next_index;
+        Some(Box::new(stmt))
+    };
+
+    let (cond, next_index_after_cond) = parse_expression(raw, tokens, next_index, 0)?;
+    next_index = next_index_after_cond;
+
+    if !expect_syntax(tokens, next_index, ";") {
+        return parse_error(tokens, next_index, "Expected ';' after for-loop condition:");
+    }
     next_index += 1;
-    Some((Statement::Expression(expr), next_index))
+
+    // The step clause has no terminator of its own (it's followed directly by
+    // `do`), so try it as a bare assignment (`i = i + 1`) before falling back
+    // to a plain expression.
+    let step = if expect_keyword(tokens, next_index, "do") {
+        None
+    } else if let Ok((assign, next_next_index)) = parse_assign_expr(raw, tokens, next_index) {
+        next_index = next_next_index;
+        Some(Box::new(Statement::Assign(assign)))
+    } else {
+        let (expr, next_next_index) = parse_expression(raw, tokens, next_index, 0)?;
+        next_index = next_next_index;
+        Some(Box::new(Statement::Expression(expr)))
+    };
+
+    if !expect_keyword(tokens, next_index, "do") {
+        return parse_error(tokens, next_index, "Expected 'do' after for-loop step:");
+    }
+
+    let (body, next_index) = parse_block(raw, tokens, next_index + 1, &["end"], errors);
+
+    if !expect_keyword(tokens, next_index, "end") {
+        return parse_error(tokens, next_index, "Expected 'end' after for-loop body:");
+    }
+
+    Ok((
+        Statement::For(For {
+            init,
+            cond,
+            step,
+            body,
+        }),
+        next_index + 1,
+    ))
+}
+
+fn parse_break(raw: &[char], tokens: &[Token], index: usize, _errors: &mut Vec<ParseError>) -> Result<(Statement, usize), ParseError> {
+    if !expect_keyword(tokens, index, "break") {
+        return parse_error(tokens, index, "Expected 'break' keyword:");
+    }
+
+    let next_index = index + 1;
+    if !expect_syntax(tokens, next_index, ";") {
+        return parse_error(tokens, next_index, "Expected semicolon after 'break':");
+    }
+
+    Ok((Statement::Break, next_index + 1))
 }
 
+fn parse_continue(raw: &[char], tokens: &[Token], index: usize, _errors: &mut Vec<ParseError>) -> Result<(Statement, usize), ParseError> {
+    if !expect_keyword(tokens, index, "continue") {
+        return parse_error(tokens, index, "Expected 'continue' keyword:");
+    }
 
-fn parse_expression(raw: &[char], tokens: &[Token], index: usize) -> Option<(Expression, usize)> {
+    let next_index = index + 1;
+    if !expect_syntax(tokens, next_index, ";") {
+        return parse_error(tokens, next_index, "Expected semicolon after 'continue':");
+    }
+
+    Ok((Statement::Continue, next_index + 1))
+}
+
+// Left binding power for each binary operator, used by the precedence-climbing
+// loop in `parse_expression`. Operators not listed here are not binary operators.
+// `and`/`or` sit below comparisons so they bind more loosely than everything else.
+fn binding_power(operator: &str) -> Option<u8> {
+    match operator {
+        "or" => Some(1),
+        "and" => Some(2),
+        "==" | "~=" => Some(3),
+        "<" | ">" => Some(4),
+        "+" | "-" => Some(5),
+        "*" | "/" => Some(6),
+        _ => None,
+    }
+}
+
+fn parse_primary(raw: &[char], tokens: &[Token], index: usize) -> Result<(Expression, usize), ParseError> {
     if index >= tokens.len() {
-        return None;
+        return parse_error(tokens, index, "Expected an expression:");
     }
 
     let t = tokens[index].clone();
+
+    if t.kind == TokenKind::Keyword && (t.value == "true" || t.value == "false") {
+        return Ok((Expression::Literal(Literal::Boolean(t)), index + 1));
+    }
+
+    if t.kind == TokenKind::Keyword && t.value == "nil" {
+        return Ok((Expression::Literal(Literal::Nil(t)), index + 1));
+    }
+
+    if (t.kind == TokenKind::Operator && t.value == "-") || (t.kind == TokenKind::Keyword && t.value == "not") {
+        let (operand, next_index) = parse_expression(raw, tokens, index + 1, 7)?;
+        return Ok((
+            Expression::Unary(Unary {
+                operator: t,
+                operand: Box::new(operand),
+            }),
+            next_index,
+        ));
+    }
+
+    if expect_syntax(tokens, index, "(") {
+        let (inner, next_index) = parse_expression(raw, tokens, index + 1, 0)?;
+
+        if !expect_syntax(tokens, next_index, ")") {
+            return parse_error(tokens, next_index, "Expected closing parenthesis after grouped expression:");
+        }
+
+        return Ok((Expression::Grouping(Box::new(inner)), next_index + 1));
+    }
+
     let left = match t.kind {
         TokenKind::Number => Expression::Literal(Literal::Number(t)),
         TokenKind::Identifier => Expression::Literal(Literal::Identifier(t)),
+        TokenKind::String => Expression::Literal(Literal::String(t)),
         _ => {
-            return None;
+            return parse_error(tokens, index, "Expected an expression:");
         }
     };
 
@@ -167,36 +576,20 @@ fn parse_expression(raw: &[char], tokens: &[Token], index: usize) -> Option<(Exp
         while !expect_syntax(tokens, next_index, ")") {
             if arguments.is_empty() {
                 if !expect_syntax(tokens, next_index, ",") {
-                    println!(
-                        "{}",
-                        tokens[next_index]
-                            .loc
-                            .debug(raw, "Expected comma between function call arguments:")
-                    );
-                    return None;
+                    return parse_error(tokens, next_index, "Expected comma between function call arguments:");
                 }
 
                 next_index += 1; // Skip past comma
             }
 
-            let res = parse_expression(raw, tokens, next_index);
-            if let Some((arg, next_next_index)) = res {
-                next_index = next_next_index;
-                arguments.push(arg);
-            } else {
-                println!(
-                    "{}",
-                    tokens[next_index]
-                        .loc
-                        .debug(raw, "Expected valid expression in function call arguments:")
-                );
-                return None;
-            }
+            let (arg, next_next_index) = parse_expression(raw, tokens, next_index, 0)?;
+            next_index = next_next_index;
+            arguments.push(arg);
         }
 
         next_index += 1; // Skip past closing paren
 
-        return Some((
+        return Ok((
             Expression::FunctionCall(FunctionCall {
                 name: tokens[index].clone(),
                 arguments,
@@ -205,47 +598,161 @@ fn parse_expression(raw: &[char], tokens: &[Token], index: usize) -> Option<(Exp
         ));
     }
 
-        // Might be a literal expression
-    if next_index >= tokens.len() || tokens[next_index].clone().kind != TokenKind::Operator {
-        return Some((left, next_index));
+    Ok((left, next_index))
+}
+
+fn parse_expression(raw: &[char], tokens: &[Token], index: usize, min_bp: u8) -> Result<(Expression, usize), ParseError> {
+    let (mut left, mut next_index) = parse_primary(raw, tokens, index)?;
+
+    loop {
+        if next_index >= tokens.len() {
+            break;
+        }
+
+        let candidate = tokens[next_index].clone();
+        if candidate.kind != TokenKind::Operator && candidate.kind != TokenKind::Keyword {
+            break;
+        }
+
+        let op = candidate;
+        let lbp = match binding_power(op.value.as_str()) {
+            Some(lbp) => lbp,
+            None => break,
+        };
+
+        if lbp < min_bp {
+            break;
+        }
+
+        next_index += 1; // Skip past op
+
+        let (right, next_next_index) = parse_expression(raw, tokens, next_index, lbp + 1)?;
+        next_index = next_next_index;
+
+        // `and`/`or` get their own node shape so an evaluator can short-circuit
+        // them instead of treating them like an eager arithmetic/comparison op.
+        left = if op.value == "and" || op.value == "or" {
+            Expression::Logical(Logical {
+                left: Box::new(left),
+                right: Box::new(right),
+                operator: op,
+            })
+        } else {
+            Expression::BinaryOperation(BinaryOperation {
+                left: Box::new(left),
+                right: Box::new(right),
+                operator: op,
+            })
+        };
+    }
+
+    Ok((left, next_index))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lex::lex;
+
+    fn parse_source(source: &str) -> Result<Ast, Vec<ParseError>> {
+        let raw: Vec<char> = source.chars().collect();
+        let tokens = lex(&raw);
+        parse(&raw, tokens)
+    }
+
+    #[test]
+    fn for_loop_assigns_a_mutable_counter_in_init_and_step() {
+        let ast = parse_source("for i = 0; i < 10; i = i + 1 do break; end")
+            .expect("for-loop with an assignment init and step should parse");
+
+        let Statement::For(for_) = &ast[0] else {
+            panic!("expected a For statement, got {:?}", ast[0]);
+        };
+
+        let Some(init) = &for_.init else {
+            panic!("expected a non-empty for-loop init");
+        };
+        assert!(matches!(**init, Statement::Assign(_)), "init should be an assignment: {:?}", init);
+
+        let Some(step) = &for_.step else {
+            panic!("expected a non-empty for-loop step");
+        };
+        assert!(matches!(**step, Statement::Assign(_)), "step should be an assignment: {:?}", step);
     }
 
-    // Otherwise is a binary operation
-    let op = tokens[next_index].clone();
-    next_index += 1; // Skip past op
+    fn parse_expr(source: &str) -> Expression {
+        let ast = parse_source(source).expect("expression should parse");
+        let Statement::Expression(expr) = ast.into_iter().next().expect("expected at least one statement") else {
+            panic!("expected an expression statement");
+        };
+        expr
+    }
 
-    if next_index >= tokens.len() {
-        println!(
-            "{}",
-            tokens[next_index]
-                .loc
-                .debug(raw, "Expected valid right hand side binary operand:")
+    #[test]
+    fn multiplication_binds_tighter_than_addition() {
+        // 1 + 2 * 3 should parse as 1 + (2 * 3), i.e. `+` at the top with `*` nested on its right.
+        let expr = parse_expr("1 + 2 * 3;");
+
+        let Expression::BinaryOperation(add) = &expr else {
+            panic!("expected a top-level BinaryOperation, got {:?}", expr);
+        };
+        assert_eq!(add.operator.value, "+");
+        assert!(matches!(*add.left, Expression::Literal(Literal::Number(_))));
+        assert!(
+            matches!(*add.right, Expression::BinaryOperation(ref mul) if mul.operator.value == "*"),
+            "right operand of + should be the * term: {:?}",
+            add.right
         );
-        return None;
     }
 
-    let rtoken = tokens[next_index].clone();
-        let right = match rtoken.kind {
-        TokenKind::Number => Expression::Literal(Literal::Number(rtoken)),
-        TokenKind::Identifier => Expression::Literal(Literal::Identifier(rtoken)),
-        _ => {
-            println!(
-                "{}",
-                rtoken
-                    .loc
-                    .debug(raw, "Expected valid right hand side binary operand:")
-            );
-            return None;
-        }
-    };
-    next_index += 1; // Skip past right hand operand
+    #[test]
+    fn not_binds_tighter_than_and() {
+        // `not a and b` should parse as `(not a) and b`, not `not (a and b)`.
+        let expr = parse_expr("not a and b;");
+
+        let Expression::Logical(logical) = &expr else {
+            panic!("expected a top-level Logical node, got {:?}", expr);
+        };
+        assert_eq!(logical.operator.value, "and");
+        assert!(
+            matches!(*logical.left, Expression::Unary(ref unary) if unary.operator.value == "not"),
+            "left operand of and should be the not term: {:?}",
+            logical.left
+        );
+        assert!(matches!(*logical.right, Expression::Literal(Literal::Identifier(_))));
+    }
 
-    Some((
-        Expression::BinaryOperation(BinaryOperation {
-            left: Box::new(left),
-            right: Box::new(right),
-            operator: op,
-        }),
-        next_index,
-    ))
+    #[test]
+    fn parentheses_override_precedence() {
+        // (a + b) * c should keep the grouped addition on the left of the multiplication.
+        let expr = parse_expr("(a + b) * c;");
+
+        let Expression::BinaryOperation(mul) = &expr else {
+            panic!("expected a top-level BinaryOperation, got {:?}", expr);
+        };
+        assert_eq!(mul.operator.value, "*");
+        assert!(
+            matches!(*mul.left, Expression::Grouping(ref inner) if matches!(**inner, Expression::BinaryOperation(ref add) if add.operator.value == "+")),
+            "left operand of * should be the grouped + term: {:?}",
+            mul.left
+        );
+        assert!(matches!(*mul.right, Expression::Literal(Literal::Identifier(_))));
+    }
+
+    #[test]
+    fn recovers_from_an_error_inside_a_nested_block_and_keeps_parsing() {
+        // The malformed `1 + ;` inside the while body should be recorded as a
+        // single error and skipped, without the recovery overrunning the
+        // while loop's own `end` and without losing the valid `break;` after it.
+        let raw: Vec<char> = "while true do 1 + ; end break;".chars().collect();
+        let tokens = lex(&raw);
+        let mut errors = vec![];
+        let (ast, next_index) = parse_block(&raw, &tokens, 0, &[], &mut errors);
+
+        assert_eq!(errors.len(), 1, "expected exactly one recorded error, got {:?}", errors);
+        assert_eq!(next_index, tokens.len());
+        assert_eq!(ast.len(), 2, "expected the while loop and the trailing break to both parse: {:?}", ast);
+        assert!(matches!(ast[0], Statement::While(_)), "expected a While statement, got {:?}", ast[0]);
+        assert!(matches!(ast[1], Statement::Break), "expected a Break statement, got {:?}", ast[1]);
+    }
 }